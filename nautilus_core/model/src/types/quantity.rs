@@ -18,7 +18,8 @@ use std::{
     collections::hash_map::DefaultHasher,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
-    ops::{Add, AddAssign, Deref, Mul, MulAssign, Sub, SubAssign},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Deref, Mul, MulAssign, RangeInclusive, Sub, SubAssign},
     str::FromStr,
 };
 
@@ -26,45 +27,184 @@ use anyhow::Result;
 use nautilus_core::{
     correctness::check_f64_in_range_inclusive, parsing::precision_from_str, python::to_pyvalue_err,
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 use rust_decimal::Decimal;
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize};
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use crate::types::fixed::fixed_u64_to_f64;
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
 
 #[repr(C)]
-#[derive(Copy, Clone, Eq, Default)]
-#[pyclass]
-pub struct Quantity {
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[pyclass(eq, eq_int)]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    HalfUp,
+    HalfEven,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Constraints
+////////////////////////////////////////////////////////////////////////////////
+
+/// A compile-time restriction on the domain of values a [`Quantity`] may hold.
+pub trait QuantityConstraint {
+    /// The inclusive range of `f64` values permitted by this constraint.
+    fn range() -> RangeInclusive<f64> {
+        QUANTITY_MIN..=QUANTITY_MAX
+    }
+
+    fn validate_raw(_raw: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn name() -> &'static str;
+}
+
+/// Checks `raw` against both `C::range()` and `C::validate_raw`, the two
+/// independent axes a [`QuantityConstraint`] can restrict.
+fn validate_constraint<C: QuantityConstraint>(raw: u64) -> Result<()> {
+    let range = C::range();
+    check_f64_in_range_inclusive(
+        fixed_u64_to_f64(raw),
+        *range.start(),
+        *range.end(),
+        "`Quantity` value",
+    )?;
+    C::validate_raw(raw)
+}
+
+/// The default constraint: any value in `QUANTITY_MIN..=QUANTITY_MAX` is permitted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Unbounded;
+
+impl QuantityConstraint for Unbounded {
+    fn name() -> &'static str {
+        "Unbounded"
+    }
+}
+
+/// A constraint requiring the value to be strictly greater than zero.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PositiveQuantity;
+
+impl QuantityConstraint for PositiveQuantity {
+    fn validate_raw(raw: u64) -> Result<()> {
+        if raw == 0 {
+            anyhow::bail!("`PositiveQuantity` value must be greater than zero");
+        }
+        Ok(())
+    }
+
+    fn name() -> &'static str {
+        "PositiveQuantity"
+    }
+}
+
+/// A constraint requiring `raw` to be a multiple of `STEP_RAW`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LotSize<const STEP_RAW: u64>;
+
+impl<const STEP_RAW: u64> QuantityConstraint for LotSize<STEP_RAW> {
+    fn validate_raw(raw: u64) -> Result<()> {
+        if STEP_RAW == 0 {
+            anyhow::bail!("`LotSize<0>` is not a valid step size");
+        }
+        if raw % STEP_RAW != 0 {
+            anyhow::bail!(
+                "`LotSize<{STEP_RAW}>` value with raw {raw} is not a multiple of the step size"
+            );
+        }
+        Ok(())
+    }
+
+    fn name() -> &'static str {
+        "LotSize"
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Quantity
+////////////////////////////////////////////////////////////////////////////////
+
+/// A quantity with a fixed-precision `raw` representation, generic over a
+/// [`QuantityConstraint`] enforced in `new`/`try_from_raw`/the `checked_*` family.
+///
+/// `C` defaults to [`Unbounded`], so code that writes the bare `Quantity` type gets
+/// `Quantity<Unbounded>`, which keeps the unchecked `Add`/`Sub`/`Mul` operators (their
+/// result takes the larger of the two operand precisions) and the infallible
+/// [`Quantity::from_raw`].
+/// Narrower constraints such as [`PositiveQuantity`] or [`LotSize`] only support the
+/// fallible `checked_*`/`new`/`try_from_raw` constructors, since a constraint can be
+/// violated by construction, arithmetic, or conversion alike.
+#[repr(C)]
+#[derive(Copy, Clone, Eq)]
+pub struct Quantity<C: QuantityConstraint = Unbounded> {
     pub raw: u64,
     pub precision: u8,
+    _constraint: PhantomData<C>,
 }
 
-impl Quantity {
+impl<C: QuantityConstraint> Quantity<C> {
     pub fn new(value: f64, precision: u8) -> Result<Self> {
+        // Matches the rounding convention of the `f64_to_fixed_u64` conversion this replaced:
+        // plain `f64::round()` is half-away-from-zero, which for the non-negative values
+        // `Quantity` holds is exactly `HalfUp`. Don't default to `HalfEven` here: it's a
+        // different tie-breaking rule and would silently change existing callers' results
+        // on tie values (e.g. `new(2.5, 0)` would round to `2` instead of `3`).
+        Self::new_with_mode(value, precision, RoundingMode::HalfUp)
+    }
+
+    pub fn new_with_mode(value: f64, precision: u8, mode: RoundingMode) -> Result<Self> {
         check_f64_in_range_inclusive(value, QUANTITY_MIN, QUANTITY_MAX, "`Quantity` value")?;
         check_fixed_precision(precision)?;
 
+        let scaled = value * 10f64.powi(i32::from(precision));
+        let rounded = match mode {
+            RoundingMode::Floor => scaled.floor(),
+            RoundingMode::Ceil => scaled.ceil(),
+            // `scaled` is never negative: `value` is already checked against
+            // `QUANTITY_MIN` (0.0) above, so there is no negative half to round away from zero.
+            RoundingMode::HalfUp => (scaled + 0.5).floor(),
+            RoundingMode::HalfEven => scaled.round_ties_even(),
+        };
+        let raw = (rounded as u64) * u64::pow(10, u32::from(FIXED_PRECISION - precision));
+        validate_constraint::<C>(raw)?;
+
         Ok(Self {
-            raw: f64_to_fixed_u64(value, precision),
+            raw,
             precision,
+            _constraint: PhantomData,
         })
     }
 
-    #[must_use]
-    pub fn from_raw(raw: u64, precision: u8) -> Self {
-        check_fixed_precision(precision).unwrap();
-        Self { raw, precision }
+    /// Fallible constructor from an already-scaled `raw` value, validating both
+    /// `precision` and the `C` constraint. Prefer [`Quantity::from_raw`] for the common,
+    /// infallible `Unbounded` case; this is for constrained `C` where construction can fail.
+    pub fn try_from_raw(raw: u64, precision: u8) -> Result<Self> {
+        check_fixed_precision(precision)?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision,
+            _constraint: PhantomData,
+        })
     }
 
-    #[must_use]
-    pub fn zero(precision: u8) -> Self {
-        check_fixed_precision(precision).unwrap();
-        Quantity::new(0.0, precision).unwrap()
+    /// Re-validates `self` against a different constraint, converting between them.
+    pub fn constrain<C2: QuantityConstraint>(self) -> Result<Quantity<C2>> {
+        validate_constraint::<C2>(self.raw)?;
+
+        Ok(Quantity {
+            raw: self.raw,
+            precision: self.precision,
+            _constraint: PhantomData,
+        })
     }
 
     #[must_use]
@@ -88,58 +228,288 @@ impl Quantity {
         let rescaled_raw = self.raw / u64::pow(10, (FIXED_PRECISION - self.precision) as u32);
         Decimal::from_i128_with_scale(rescaled_raw as i128, self.precision as u32)
     }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 9] {
+        let mut bytes = [0u8; 9];
+        bytes[..8].copy_from_slice(&self.raw.to_le_bytes());
+        bytes[8] = self.precision;
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 9 {
+            anyhow::bail!(
+                "`Quantity::from_bytes` expected 9 bytes, received {}",
+                bytes.len()
+            );
+        }
+
+        let mut raw_bytes = [0u8; 8];
+        raw_bytes.copy_from_slice(&bytes[..8]);
+        let raw = u64::from_le_bytes(raw_bytes);
+        let precision = bytes[8];
+        check_fixed_precision(precision)?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision,
+            _constraint: PhantomData,
+        })
+    }
+
+    #[must_use]
+    pub fn to_bytes_packed(&self) -> Vec<u8> {
+        let raw_bytes = self.raw.to_le_bytes();
+        let len = raw_bytes.len() - (self.raw.leading_zeros() as usize / 8);
+
+        let mut bytes = Vec::with_capacity(2 + len);
+        bytes.push(len as u8);
+        bytes.extend_from_slice(&raw_bytes[..len]);
+        bytes.push(self.precision);
+        bytes
+    }
+
+    pub fn from_bytes_packed(bytes: &[u8]) -> Result<Self> {
+        let len = *bytes
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("`Quantity::from_bytes_packed` received empty input"))?
+            as usize;
+
+        if len > 8 {
+            anyhow::bail!("`Quantity::from_bytes_packed` length prefix {len} exceeds 8 bytes");
+        }
+        if bytes.len() != len + 2 {
+            anyhow::bail!(
+                "`Quantity::from_bytes_packed` expected {} bytes, received {}",
+                len + 2,
+                bytes.len()
+            );
+        }
+
+        let mut raw_bytes = [0u8; 8];
+        raw_bytes[..len].copy_from_slice(&bytes[1..1 + len]);
+        let raw = u64::from_le_bytes(raw_bytes);
+        let precision = bytes[1 + len];
+        check_fixed_precision(precision)?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision,
+            _constraint: PhantomData,
+        })
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Result<Self> {
+        let raw = self.raw.checked_add(rhs.raw).ok_or_else(|| {
+            anyhow::anyhow!(
+                "`Quantity` addition overflowed: {} + {}",
+                self.raw,
+                rhs.raw
+            )
+        })?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self> {
+        let raw = self.raw.checked_sub(rhs.raw).ok_or_else(|| {
+            anyhow::anyhow!(
+                "`Quantity` subtraction underflowed below `QUANTITY_MIN`: {} - {}",
+                self.raw,
+                rhs.raw
+            )
+        })?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
+        })
+    }
+
+    // Intermediate product computed in `u128` to avoid overflowing `u64`.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self> {
+        let product = u128::from(self.raw) * u128::from(rhs.raw) / u128::from(FIXED_SCALAR as u64);
+        let raw = u64::try_from(product).map_err(|_| {
+            anyhow::anyhow!(
+                "`Quantity` multiplication overflowed `u64`: {} * {}",
+                self.raw,
+                rhs.raw
+            )
+        })?;
+        validate_constraint::<C>(raw)?;
+
+        Ok(Self {
+            raw,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
+        })
+    }
+
+    pub fn checked_add_strict(self, rhs: Self) -> Result<Self> {
+        check_matching_precision(self.precision, rhs.precision)?;
+        self.checked_add(rhs)
+    }
+
+    pub fn checked_sub_strict(self, rhs: Self) -> Result<Self> {
+        check_matching_precision(self.precision, rhs.precision)?;
+        self.checked_sub(rhs)
+    }
+
+    pub fn checked_mul_strict(self, rhs: Self) -> Result<Self> {
+        check_matching_precision(self.precision, rhs.precision)?;
+        self.checked_mul(rhs)
+    }
+}
+
+fn check_matching_precision(precision1: u8, precision2: u8) -> Result<()> {
+    if precision1 != precision2 {
+        anyhow::bail!("`Quantity` precisions did not match: {precision1} != {precision2}");
+    }
+    Ok(())
 }
 
-impl From<Quantity> for f64 {
-    fn from(qty: Quantity) -> Self {
+impl Default for Quantity<Unbounded> {
+    fn default() -> Self {
+        Self {
+            raw: 0,
+            precision: 0,
+            _constraint: PhantomData,
+        }
+    }
+}
+
+impl Quantity<Unbounded> {
+    #[must_use]
+    pub fn zero(precision: u8) -> Self {
+        check_fixed_precision(precision).unwrap();
+        Quantity::new(0.0, precision).unwrap()
+    }
+
+    /// Constructs a `Quantity` directly from an already-scaled `raw` value, e.g. raw ticks
+    /// or wire data that has already been validated upstream.
+    ///
+    /// Infallible and unconstrained, matching this function's behavior before the
+    /// [`QuantityConstraint`] system was introduced, so existing callers across the
+    /// workspace that construct `Quantity` from raw values are unaffected. Use
+    /// [`Quantity::try_from_raw`] to construct a constrained `Quantity<C>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` exceeds [`FIXED_PRECISION`].
+    #[must_use]
+    pub fn from_raw(raw: u64, precision: u8) -> Self {
+        check_fixed_precision(precision).unwrap();
+        Self {
+            raw,
+            precision,
+            _constraint: PhantomData,
+        }
+    }
+}
+
+impl<C: QuantityConstraint> From<Quantity<C>> for f64 {
+    fn from(qty: Quantity<C>) -> Self {
         qty.as_f64()
     }
 }
 
-impl From<&Quantity> for f64 {
-    fn from(qty: &Quantity) -> Self {
+impl<C: QuantityConstraint> From<&Quantity<C>> for f64 {
+    fn from(qty: &Quantity<C>) -> Self {
         qty.as_f64()
     }
 }
 
-impl FromStr for Quantity {
+impl<C: QuantityConstraint> FromStr for Quantity<C> {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let float_from_input = input
-            .parse::<f64>()
-            .map_err(|e| format!("Cannot parse `input` string '{input}' as f64: {e}"))?;
+        let precision = precision_from_str(input);
+
+        let (integer_part, fractional_part) = match input.split_once('.') {
+            Some((integer_part, fractional_part)) => (integer_part, fractional_part),
+            None => (input, ""),
+        };
+
+        if fractional_part.len() > FIXED_PRECISION as usize {
+            return Err(format!(
+                "Cannot parse `input` string '{input}': more than {FIXED_PRECISION} fractional digits",
+            ));
+        }
+
+        let integer_value = integer_part
+            .parse::<u64>()
+            .map_err(|e| format!("Cannot parse `input` string '{input}' as integer part: {e}"))?;
+
+        let mut fractional_digits = fractional_part.to_string();
+        fractional_digits
+            .push_str(&"0".repeat(FIXED_PRECISION as usize - fractional_part.len()));
+        let fractional_value = fractional_digits
+            .parse::<u64>()
+            .map_err(|e| format!("Cannot parse `input` string '{input}' as fractional part: {e}"))?;
+
+        let raw = integer_value
+            .checked_mul(FIXED_SCALAR as u64)
+            .and_then(|scaled| scaled.checked_add(fractional_value))
+            .ok_or_else(|| format!("Cannot parse `input` string '{input}': value overflowed"))?;
+
+        check_fixed_precision(precision).map_err(|e| e.to_string())?;
+        check_f64_in_range_inclusive(
+            fixed_u64_to_f64(raw),
+            QUANTITY_MIN,
+            QUANTITY_MAX,
+            "`Quantity` value",
+        )
+        .map_err(|e| e.to_string())?;
+        validate_constraint::<C>(raw).map_err(|e| e.to_string())?;
 
-        Self::new(float_from_input, precision_from_str(input))
-            .map_err(|e: anyhow::Error| e.to_string())
+        Ok(Self {
+            raw,
+            precision,
+            _constraint: PhantomData,
+        })
     }
 }
 
-impl From<&str> for Quantity {
+impl From<&str> for Quantity<Unbounded> {
     fn from(input: &str) -> Self {
         Self::from_str(input).unwrap()
     }
 }
 
-impl From<i64> for Quantity {
+impl From<i64> for Quantity<Unbounded> {
     fn from(input: i64) -> Self {
         Self::new(input as f64, 0).unwrap()
     }
 }
 
-impl Hash for Quantity {
+impl<C: QuantityConstraint> Hash for Quantity<C> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.raw.hash(state)
     }
 }
 
-impl PartialEq for Quantity {
+impl<C: QuantityConstraint> PartialEq for Quantity<C> {
+    // Intentionally compares `raw` only, so `Quantity::new(1.0, 1) == Quantity::new(1.0, 2)`
+    // even though their `to_string()` differ. Out of scope for the `checked_*_strict` family
+    // above: those add opt-in strict *arithmetic*, they don't change `Eq`, which stays
+    // precision-insensitive to match `Ord`/`Hash` and avoid breaking existing callers that
+    // rely on `Quantity` equality being a pure `raw` comparison.
     fn eq(&self, other: &Self) -> bool {
         self.raw == other.raw
     }
 }
 
-impl PartialOrd for Quantity {
+impl<C: QuantityConstraint> PartialOrd for Quantity<C> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -161,13 +531,13 @@ impl PartialOrd for Quantity {
     }
 }
 
-impl Ord for Quantity {
+impl<C: QuantityConstraint> Ord for Quantity<C> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.raw.cmp(&other.raw)
     }
 }
 
-impl Deref for Quantity {
+impl<C: QuantityConstraint> Deref for Quantity<C> {
     type Target = u64;
 
     fn deref(&self) -> &Self::Target {
@@ -175,86 +545,131 @@ impl Deref for Quantity {
     }
 }
 
-impl Add for Quantity {
+impl Add for Quantity<Unbounded> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Self {
             raw: self.raw + rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
         }
     }
 }
 
-impl Sub for Quantity {
+impl Sub for Quantity<Unbounded> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
             raw: self.raw - rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
         }
     }
 }
 
-impl Mul for Quantity {
+impl Mul for Quantity<Unbounded> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
             raw: (self.raw * rhs.raw) / (FIXED_SCALAR as u64),
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
+            _constraint: PhantomData,
         }
     }
 }
 
-impl Mul<f64> for Quantity {
+impl Mul<f64> for Quantity<Unbounded> {
     type Output = f64;
     fn mul(self, rhs: f64) -> Self::Output {
         self.as_f64() * rhs
     }
 }
 
-impl From<Quantity> for u64 {
-    fn from(value: Quantity) -> Self {
+impl<C: QuantityConstraint> From<Quantity<C>> for u64 {
+    fn from(value: Quantity<C>) -> Self {
         value.raw
     }
 }
 
-impl From<&Quantity> for u64 {
-    fn from(value: &Quantity) -> Self {
+impl<C: QuantityConstraint> From<&Quantity<C>> for u64 {
+    fn from(value: &Quantity<C>) -> Self {
         value.raw
     }
 }
 
-impl<T: Into<u64>> AddAssign<T> for Quantity {
+impl<T: Into<u64>> AddAssign<T> for Quantity<Unbounded> {
     fn add_assign(&mut self, other: T) {
         self.raw += other.into();
     }
 }
 
-impl<T: Into<u64>> SubAssign<T> for Quantity {
+impl<T: Into<u64>> SubAssign<T> for Quantity<Unbounded> {
     fn sub_assign(&mut self, other: T) {
         self.raw -= other.into();
     }
 }
 
-impl<T: Into<u64>> MulAssign<T> for Quantity {
+impl<T: Into<u64>> MulAssign<T> for Quantity<Unbounded> {
     fn mul_assign(&mut self, other: T) {
         self.raw *= other.into();
     }
 }
 
-impl Debug for Quantity {
+impl<C: QuantityConstraint> Debug for Quantity<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:.*}", self.precision as usize, self.as_f64())
+        write!(f, "{}", format_raw(self.raw, self.precision))
     }
 }
 
-impl Display for Quantity {
+impl<C: QuantityConstraint> Display for Quantity<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:.*}", self.precision as usize, self.as_f64())
+        write!(f, "{}", format_raw(self.raw, self.precision))
+    }
+}
+
+fn format_raw(raw: u64, precision: u8) -> String {
+    // `fractional_str` is always exactly `FIXED_PRECISION` bytes, so clamp rather than trust
+    // `precision` is in range: a `Quantity` built via `Quantity { raw, precision, .. }` directly,
+    // or handed across FFI, bypasses the `check_fixed_precision` validation in `new`/`from_raw`.
+    let precision = precision.min(FIXED_PRECISION) as usize;
+
+    let scalar = FIXED_SCALAR as u64;
+    let mut integer_part = raw / scalar;
+    let fractional_part = raw % scalar;
+
+    let fractional_str = format!("{fractional_part:0>width$}", width = FIXED_PRECISION as usize);
+    let mut digits = fractional_str.as_bytes()[..precision].to_vec();
+    let round_up = fractional_str
+        .as_bytes()
+        .get(precision)
+        .is_some_and(|&d| d >= b'5');
+
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if !carry {
+                break;
+            }
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+            }
+        }
+        if carry {
+            integer_part += 1;
+        }
+    }
+
+    if precision == 0 {
+        return integer_part.to_string();
     }
+
+    format!("{integer_part}.{}", String::from_utf8(digits).unwrap())
 }
 
-impl Serialize for Quantity {
+impl<C: QuantityConstraint> Serialize for Quantity<C> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -263,79 +678,165 @@ impl Serialize for Quantity {
     }
 }
 
-impl<'de> Deserialize<'de> for Quantity {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+impl<'de, C: QuantityConstraint> Deserialize<'de> for Quantity<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let qty_str: &str = Deserialize::deserialize(_deserializer)?;
-        let qty: Quantity = qty_str.into();
-        Ok(qty)
+        let qty_str: &str = Deserialize::deserialize(deserializer)?;
+        qty_str.parse().map_err(D::Error::custom)
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // Python API
 ////////////////////////////////////////////////////////////////////////////////
+// `pyo3`'s `#[pyclass]` macro cannot target a generic struct: it needs exactly one
+// concrete type to register as a Python class. `PyQuantity` is a minimal concrete
+// mirror of `Quantity<Unbounded>` — the only constraint Python call sites need — and
+// converts to/from it at the boundary, so `Quantity<C>` itself stays fully generic.
+#[cfg(feature = "python")]
+#[derive(Copy, Clone, Default)]
+#[pyclass(name = "Quantity")]
+pub struct PyQuantity(Quantity<Unbounded>);
+
+#[cfg(feature = "python")]
+impl From<Quantity<Unbounded>> for PyQuantity {
+    fn from(value: Quantity<Unbounded>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<PyQuantity> for Quantity<Unbounded> {
+    fn from(value: PyQuantity) -> Self {
+        value.0
+    }
+}
+
 #[cfg(feature = "python")]
 #[pymethods]
-impl Quantity {
+impl PyQuantity {
     #[new]
     fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Quantity::new(value, precision).map_err(to_pyvalue_err)
+        Quantity::new(value, precision)
+            .map(Self)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "new_with_mode")]
+    fn py_new_with_mode(value: f64, precision: u8, mode: RoundingMode) -> PyResult<Self> {
+        Quantity::new_with_mode(value, precision, mode)
+            .map(Self)
+            .map_err(to_pyvalue_err)
     }
 
     fn __hash__(&self) -> isize {
         let mut h = DefaultHasher::new();
-        self.hash(&mut h);
+        self.0.hash(&mut h);
         h.finish() as isize
     }
 
     fn __str__(&self) -> String {
-        self.to_string()
+        self.0.to_string()
     }
 
     fn __repr__(&self) -> String {
-        format!("{self:?}")
+        format!("{:?}", self.0)
     }
 
     #[staticmethod]
     #[pyo3(name = "zero")]
     #[pyo3(signature = (precision = 0))]
-    fn py_zero(precision: u8) -> PyResult<Quantity> {
-        Quantity::new(0.0, precision).map_err(to_pyvalue_err)
+    fn py_zero(precision: u8) -> PyResult<Self> {
+        Quantity::new(0.0, precision)
+            .map(Self)
+            .map_err(to_pyvalue_err)
     }
 
     #[getter]
     fn raw(&self) -> u64 {
-        self.raw
+        self.0.raw
     }
 
     #[getter]
     fn precision(&self) -> u8 {
-        self.precision
+        self.0.precision
     }
 
     #[pyo3(name = "as_double")]
     fn py_as_double(&self) -> f64 {
-        self.as_f64()
+        self.0.as_f64()
     }
 
     #[staticmethod]
     #[pyo3(name = "from_int")]
-    fn py_from_int(value: u64) -> PyResult<Quantity> {
-        Quantity::new(value as f64, 0).map_err(to_pyvalue_err)
+    fn py_from_int(value: u64) -> PyResult<Self> {
+        Quantity::new(value as f64, 0)
+            .map(Self)
+            .map_err(to_pyvalue_err)
     }
 
     #[staticmethod]
     #[pyo3(name = "from_str")]
-    fn py_from_str(value: &str) -> PyResult<Quantity> {
-        Quantity::from_str(value).map_err(to_pyvalue_err)
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Quantity::from_str(value).map(Self).map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "as_decimal")]
     fn py_as_decimal(&self) -> Decimal {
-        self.as_decimal()
+        self.0.as_decimal()
+    }
+
+    #[pyo3(name = "checked_add")]
+    fn py_checked_add(&self, other: Self) -> PyResult<Self> {
+        self.0.checked_add(other.0).map(Self).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "checked_sub")]
+    fn py_checked_sub(&self, other: Self) -> PyResult<Self> {
+        self.0.checked_sub(other.0).map(Self).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "checked_mul")]
+    fn py_checked_mul(&self, other: Self) -> PyResult<Self> {
+        self.0.checked_mul(other.0).map(Self).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "checked_add_strict")]
+    fn py_checked_add_strict(&self, other: Self) -> PyResult<Self> {
+        self.0
+            .checked_add_strict(other.0)
+            .map(Self)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "checked_sub_strict")]
+    fn py_checked_sub_strict(&self, other: Self) -> PyResult<Self> {
+        self.0
+            .checked_sub_strict(other.0)
+            .map(Self)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "checked_mul_strict")]
+    fn py_checked_mul_strict(&self, other: Self) -> PyResult<Self> {
+        self.0
+            .checked_mul_strict(other.0)
+            .map(Self)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "to_bytes")]
+    fn py_to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_bytes")]
+    fn py_from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Quantity::from_bytes(bytes).map(Self).map_err(to_pyvalue_err)
     }
 }
 
@@ -355,6 +856,13 @@ pub extern "C" fn quantity_from_raw(raw: u64, precision: u8) -> Quantity {
     Quantity::from_raw(raw, precision)
 }
 
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_new_with_mode(value: f64, precision: u8, mode: RoundingMode) -> Quantity {
+    // SAFETY: Assumes `value` and `precision` were properly validated
+    Quantity::new_with_mode(value, precision, mode).unwrap()
+}
+
 #[cfg(feature = "ffi")]
 #[no_mangle]
 pub extern "C" fn quantity_as_f64(qty: &Quantity) -> f64 {
@@ -385,6 +893,68 @@ pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
     a.sub_assign(b);
 }
 
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_add(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated and will not overflow
+    a.checked_add(b).unwrap()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_sub(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated and will not underflow
+    a.checked_sub(b).unwrap()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_mul(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated and will not overflow
+    a.checked_mul(b).unwrap()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_add_strict(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated to share a precision and not overflow
+    a.checked_add_strict(b).unwrap()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_sub_strict(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated to share a precision and not underflow
+    a.checked_sub_strict(b).unwrap()
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn quantity_checked_mul_strict(a: Quantity, b: Quantity) -> Quantity {
+    // SAFETY: Assumes `a` and `b` were properly validated to share a precision and not overflow
+    a.checked_mul_strict(b).unwrap()
+}
+
+/// # Safety
+///
+/// Assumes `out` points to a valid, writable buffer of at least 9 bytes.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_to_bytes(qty: &Quantity, out: *mut u8) {
+    let bytes = qty.to_bytes();
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len());
+}
+
+/// # Safety
+///
+/// Assumes `bytes` points to a valid 9-byte buffer.
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn quantity_from_bytes(bytes: *const u8) -> Quantity {
+    let slice = std::slice::from_raw_parts(bytes, 9);
+    Quantity::from_bytes(slice).unwrap()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -577,6 +1147,223 @@ mod tests {
         assert!(Quantity::new(0.9, 1).unwrap() <= Quantity::new(1.0, 1).unwrap());
     }
 
+    #[test]
+    fn test_checked_add() {
+        let quantity1 = Quantity::new(1.0, 0).unwrap();
+        let quantity2 = Quantity::new(2.0, 0).unwrap();
+        let quantity3 = quantity1.checked_add(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 3_000_000_000);
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let quantity1 = Quantity::from_raw(u64::MAX, 0);
+        let quantity2 = Quantity::from_raw(1, 0);
+        assert!(quantity1.checked_add(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let quantity1 = Quantity::new(3.0, 0).unwrap();
+        let quantity2 = Quantity::new(2.0, 0).unwrap();
+        let quantity3 = quantity1.checked_sub(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_checked_sub_underflow() {
+        let quantity1 = Quantity::new(1.0, 0).unwrap();
+        let quantity2 = Quantity::new(2.0, 0).unwrap();
+        assert!(quantity1.checked_sub(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let quantity1 = Quantity::new(2.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 1).unwrap();
+        let quantity3 = quantity1.checked_mul(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 4_000_000_000);
+    }
+
+    #[test]
+    fn test_checked_mul_overflow() {
+        let quantity1 = Quantity::from_raw(u64::MAX, 0);
+        let quantity2 = Quantity::from_raw(u64::MAX, 0);
+        assert!(quantity1.checked_mul(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_mul_near_u64_max_product_uses_u128_intermediate() {
+        // `4_300_000_000 * 4_300_000_000` overflows plain `u64` multiplication before the
+        // division by `FIXED_SCALAR`, but is valid once computed in `u128` — the case
+        // `checked_mul`'s `u128` intermediate product exists to handle.
+        let quantity1 = Quantity::from_raw(4_300_000_000, 0);
+        let quantity2 = Quantity::from_raw(4_300_000_000, 0);
+        let product = quantity1.checked_mul(quantity2).unwrap();
+        assert_eq!(product.raw, 18_490_000_000);
+    }
+
+    #[test]
+    fn test_add_mismatched_precision_takes_max() {
+        let quantity1 = Quantity::new(1.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 2).unwrap();
+        let quantity3 = quantity1 + quantity2;
+        assert_eq!(quantity3.precision, 2);
+    }
+
+    #[test]
+    fn test_sub_mismatched_precision_takes_max() {
+        let quantity1 = Quantity::new(3.0, 2).unwrap();
+        let quantity2 = Quantity::new(2.0, 1).unwrap();
+        let quantity3 = quantity1 - quantity2;
+        assert_eq!(quantity3.precision, 2);
+    }
+
+    #[test]
+    fn test_mul_mismatched_precision_takes_max() {
+        let quantity1 = Quantity::new(2.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 3).unwrap();
+        let quantity3 = quantity1 * quantity2;
+        assert_eq!(quantity3.precision, 3);
+    }
+
+    #[test]
+    fn test_checked_add_strict_mismatched_precision() {
+        let quantity1 = Quantity::new(1.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 2).unwrap();
+        assert!(quantity1.checked_add_strict(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_add_strict_matching_precision() {
+        let quantity1 = Quantity::new(1.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 1).unwrap();
+        let quantity3 = quantity1.checked_add_strict(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 3_000_000_000);
+        assert_eq!(quantity3.precision, 1);
+    }
+
+    #[test]
+    fn test_checked_sub_strict_mismatched_precision() {
+        let quantity1 = Quantity::new(3.0, 2).unwrap();
+        let quantity2 = Quantity::new(2.0, 1).unwrap();
+        assert!(quantity1.checked_sub_strict(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_sub_strict_matching_precision() {
+        let quantity1 = Quantity::new(3.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 1).unwrap();
+        let quantity3 = quantity1.checked_sub_strict(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 1_000_000_000);
+        assert_eq!(quantity3.precision, 1);
+    }
+
+    #[test]
+    fn test_checked_mul_strict_mismatched_precision() {
+        let quantity1 = Quantity::new(2.0, 1).unwrap();
+        let quantity2 = Quantity::new(2.0, 3).unwrap();
+        assert!(quantity1.checked_mul_strict(quantity2).is_err());
+    }
+
+    #[test]
+    fn test_checked_mul_strict_matching_precision() {
+        let quantity1 = Quantity::new(2.0, 1).unwrap();
+        let quantity2 = Quantity::new(3.0, 1).unwrap();
+        let quantity3 = quantity1.checked_mul_strict(quantity2).unwrap();
+        assert_eq!(quantity3.raw, 6_000_000_000);
+        assert_eq!(quantity3.precision, 1);
+    }
+
+    #[test]
+    fn test_from_str_exact_max_precision_round_trip() {
+        let input = "9999999999.123456789";
+        let qty = Quantity::from_str(input).unwrap();
+        assert_eq!(qty.precision, 9);
+        assert_eq!(qty.raw, 9_999_999_999_123_456_789);
+        assert_eq!(qty.to_string(), input);
+    }
+
+    #[test]
+    fn test_to_bytes_from_bytes() {
+        let qty = Quantity::new(0.00812, 8).unwrap();
+        let bytes = qty.to_bytes();
+        let decoded = Quantity::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, qty);
+        assert_eq!(decoded.precision, qty.precision);
+    }
+
+    #[test]
+    fn test_from_bytes_wrong_length() {
+        assert!(Quantity::from_bytes(&[0u8; 8]).is_err());
+    }
+
+    #[test]
+    fn test_display_does_not_panic_on_out_of_range_precision() {
+        // Bypasses `new`/`from_raw` validation, as a raw FFI call or struct literal could.
+        let qty = Quantity::from_raw(8_120_000, 0);
+        let qty = Quantity { precision: 250, ..qty };
+        assert_eq!(qty.to_string(), "0.008120000");
+    }
+
+    #[test]
+    fn test_to_bytes_packed_from_bytes_packed() {
+        let qty = Quantity::new(0.00812, 8).unwrap();
+        let bytes = qty.to_bytes_packed();
+        let decoded = Quantity::from_bytes_packed(&bytes).unwrap();
+        assert_eq!(decoded, qty);
+        assert_eq!(decoded.precision, qty.precision);
+    }
+
+    #[test]
+    fn test_to_bytes_packed_zero_is_compact() {
+        let qty = Quantity::zero(0);
+        let bytes = qty.to_bytes_packed();
+        assert_eq!(bytes, vec![0, 0]);
+        assert_eq!(Quantity::from_bytes_packed(&bytes).unwrap(), qty);
+    }
+
+    #[test]
+    fn test_new_with_mode_floor() {
+        let qty = Quantity::new_with_mode(1.2599, 2, RoundingMode::Floor).unwrap();
+        assert_eq!(qty.to_string(), "1.25");
+    }
+
+    #[test]
+    fn test_new_with_mode_ceil() {
+        let qty = Quantity::new_with_mode(1.2501, 2, RoundingMode::Ceil).unwrap();
+        assert_eq!(qty.to_string(), "1.26");
+    }
+
+    #[test]
+    fn test_new_with_mode_half_up() {
+        let qty = Quantity::new_with_mode(2.5, 0, RoundingMode::HalfUp).unwrap();
+        assert_eq!(qty.to_string(), "3");
+    }
+
+    #[test]
+    fn test_new_with_mode_half_even() {
+        let qty = Quantity::new_with_mode(2.5, 0, RoundingMode::HalfEven).unwrap();
+        assert_eq!(qty.to_string(), "2");
+
+        let qty = Quantity::new_with_mode(3.5, 0, RoundingMode::HalfEven).unwrap();
+        assert_eq!(qty.to_string(), "4");
+    }
+
+    #[test]
+    fn test_new_matches_new_with_mode_half_up() {
+        // `new()` defaults to `HalfUp`, matching the half-away-from-zero convention of the
+        // `f64_to_fixed_u64` conversion it replaced — not `HalfEven`.
+        assert_eq!(
+            Quantity::new(2.5, 0).unwrap(),
+            Quantity::new_with_mode(2.5, 0, RoundingMode::HalfUp).unwrap()
+        );
+        assert_eq!(
+            Quantity::new(3.5, 0).unwrap(),
+            Quantity::new_with_mode(3.5, 0, RoundingMode::HalfUp).unwrap()
+        );
+    }
+
     #[test]
     fn test_display() {
         use std::fmt::Write as FmtWrite;
@@ -587,4 +1374,115 @@ mod tests {
         assert_eq!(res, input_string);
         assert_eq!(qty.to_string(), input_string);
     }
+
+    #[test]
+    fn test_display_rounds_digits_beyond_precision() {
+        let quantity1 = Quantity::new(1.17, 2).unwrap();
+        let quantity2 = Quantity::new(1.17, 2).unwrap();
+        let product = quantity1 * quantity2;
+        assert_eq!(product.raw, 1_368_900_000);
+        assert_eq!(product.to_string(), "1.37");
+    }
+
+    #[test]
+    fn test_display_rounds_with_carry() {
+        let qty = Quantity::from_raw(1_999_999_999, 2);
+        assert_eq!(qty.to_string(), "2.00");
+    }
+
+    #[test]
+    fn test_positive_quantity_rejects_zero() {
+        assert!(Quantity::<PositiveQuantity>::new(0.0, 0).is_err());
+        assert!(Quantity::<PositiveQuantity>::new(1.0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_lot_size_zero_step_is_rejected() {
+        assert!(Quantity::<LotSize<0>>::new(1.0, 0).is_err());
+    }
+
+    #[test]
+    fn test_lot_size_rejects_non_multiple() {
+        // STEP_RAW is in raw (1e-9 scaled) units, so 1.0 at precision 0 is a multiple of
+        // a one-unit lot size but 0.5 is not.
+        type OneLot = LotSize<1_000_000_000>;
+        assert!(Quantity::<OneLot>::new(1.0, 0).is_ok());
+        assert!(Quantity::<OneLot>::new(0.5, 1).is_err());
+    }
+
+    #[test]
+    fn test_constrain_between_constraints() {
+        let qty = Quantity::<Unbounded>::new(1.0, 0).unwrap();
+        let positive: Quantity<PositiveQuantity> = qty.constrain().unwrap();
+        assert_eq!(positive.raw, qty.raw);
+
+        let zero = Quantity::<Unbounded>::new(0.0, 0).unwrap();
+        assert!(zero.constrain::<PositiveQuantity>().is_err());
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct SmallQuantity;
+
+    impl QuantityConstraint for SmallQuantity {
+        fn range() -> RangeInclusive<f64> {
+            0.0..=100.0
+        }
+
+        fn name() -> &'static str {
+            "SmallQuantity"
+        }
+    }
+
+    #[test]
+    fn test_range_constraint_rejects_out_of_range() {
+        assert!(Quantity::<SmallQuantity>::new(100.0, 0).is_ok());
+        assert!(Quantity::<SmallQuantity>::new(100.1, 1).is_err());
+    }
+
+    #[test]
+    fn test_range_constraint_enforced_outside_new() {
+        // raw 500_000_000_000 at precision 0 is value 500, outside `SmallQuantity`'s 0..=100.
+        assert!(Quantity::<SmallQuantity>::try_from_raw(500_000_000_000, 0).is_err());
+
+        let unbounded = Quantity::<Unbounded>::new(500.0, 0).unwrap();
+        assert!(unbounded.constrain::<SmallQuantity>().is_err());
+
+        let a = Quantity::<SmallQuantity>::new(60.0, 0).unwrap();
+        let b = Quantity::<SmallQuantity>::new(60.0, 0).unwrap();
+        // Arithmetically valid (60 + 60 = 120), but out of `SmallQuantity`'s range.
+        assert!(a.checked_add(b).is_err());
+    }
+
+    #[test]
+    fn test_checked_sub_revalidates_positive_constraint() {
+        let a = Quantity::<PositiveQuantity>::new(1.0, 0).unwrap();
+        let b = Quantity::<PositiveQuantity>::new(1.0, 0).unwrap();
+        // Arithmetically valid (1 - 1 = 0), but `PositiveQuantity` forbids zero.
+        assert!(a.checked_sub(b).is_err());
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct MaxRaw<const MAX: u64>;
+
+    impl<const MAX: u64> QuantityConstraint for MaxRaw<MAX> {
+        fn validate_raw(raw: u64) -> Result<()> {
+            if raw > MAX {
+                anyhow::bail!("`MaxRaw<{MAX}>` value with raw {raw} exceeds the maximum");
+            }
+            Ok(())
+        }
+
+        fn name() -> &'static str {
+            "MaxRaw"
+        }
+    }
+
+    #[test]
+    fn test_checked_add_revalidates_upper_bound_constraint() {
+        type Capped = MaxRaw<1_500_000_000>;
+        let a = Quantity::<Capped>::new(1.0, 0).unwrap();
+        let b = Quantity::<Capped>::new(1.0, 0).unwrap();
+        // Each operand is within the cap, but their sum (2.0) is not.
+        assert!(a.checked_add(b).is_err());
+    }
 }